@@ -0,0 +1,36 @@
+use bevy_ecs::prelude::*;
+
+#[derive(Resource)]
+struct Foo;
+
+#[derive(Component)]
+struct Bar;
+
+struct NotSend;
+
+fn flag_changed(mut value: impl DetectChangesMut) {
+    value.set_changed();
+}
+
+fn read_only(res: Res<Foo>) {
+    // this should fail to compile, `Res` is read-only
+    flag_changed(res);
+    //~^ E0277
+}
+
+fn read_only_direct(mut res: Res<Foo>, mut value: Ref<Bar>, mut non_send: NonSend<NotSend>) {
+    // calling `set_changed` directly on read-only wrappers should fail the same way
+    res.set_changed();
+    //~^ E0277
+    value.set_changed();
+    //~^ E0277
+    non_send.set_changed();
+    //~^ E0277
+}
+
+fn mutable(res: ResMut<Foo>) {
+    // this should *not* fail to compile
+    flag_changed(res);
+}
+
+fn main() {}
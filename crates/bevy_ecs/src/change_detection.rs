@@ -6,6 +6,7 @@ use crate::{
     system::Resource,
 };
 use bevy_ptr::{Ptr, UnsafeCellDeref};
+use bevy_utils::all_tuples;
 use core::{
     mem,
     ops::{Deref, DerefMut},
@@ -52,6 +53,11 @@ pub const MAX_CHANGE_AGE: u32 = u32::MAX - (2 * CHECK_TICK_THRESHOLD - 1);
 /// ```
 pub trait DetectChanges {
     /// Returns `true` if this value was added after the system last ran.
+    ///
+    /// Removing a component or resource and inserting it again resets its ticks, so a value
+    /// that was absent the last time the system ran but is present now is reported as added.
+    /// There is no separate "removed" signal: to react to the removal itself, use
+    /// [`RemovedComponents`](crate::removal_detection::RemovedComponents) for components.
     fn is_added(&self) -> bool;
 
     /// Returns `true` if this value was added or mutably dereferenced
@@ -59,9 +65,19 @@ pub trait DetectChanges {
     /// since the beginning of the program.
     ///
     /// To check if the value was mutably dereferenced only,
-    /// use `this.is_changed() && !this.is_added()`.
+    /// use [`changed_but_not_added`](DetectChanges::changed_but_not_added).
     fn is_changed(&self) -> bool;
 
+    /// Returns `true` if this value was mutably dereferenced after the system last ran,
+    /// but was not added in that time.
+    ///
+    /// This is equivalent to `this.is_changed() && !this.is_added()`, and can be used to
+    /// react only to mutations of pre-existing values, ignoring freshly inserted ones.
+    #[inline]
+    fn changed_but_not_added(&self) -> bool {
+        self.is_changed() && !self.is_added()
+    }
+
     /// Returns the change tick recording the time this data was most recently changed.
     ///
     /// Note that components and resources are also marked as changed upon insertion.
@@ -105,6 +121,11 @@ pub trait DetectChanges {
 ///    resource.0 = 42; // triggers change detection via [`DerefMut`]
 /// }
 /// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not support mutable change detection",
+    label = "read-only change detection",
+    note = "use a mutable smart pointer instead, such as `ResMut<T>` rather than `Res<T>`, `Mut<T>` rather than `Ref<T>`, or `NonSendMut<T>` rather than `NonSend<T>`"
+)]
 pub trait DetectChangesMut: DetectChanges {
     /// The type contained within this smart pointer
     ///
@@ -268,8 +289,138 @@ pub trait DetectChangesMut: DetectChanges {
             None
         }
     }
+
+    /// Mutates this smart pointer through `f` without triggering change detection,
+    /// and only flags it as changed if `f` returns `true`.
+    ///
+    /// This is useful when a write may not alter any meaningful state, and comparing
+    /// the whole value with [`set_if_neq`](DetectChangesMut::set_if_neq) is not an option.
+    /// Returns the value returned by `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource)]
+    /// pub struct Health {
+    ///     current: u32,
+    ///     max: u32,
+    /// }
+    ///
+    /// fn heal(mut health: ResMut<Health>) {
+    ///     // Only flag a change if healing actually restored some health.
+    ///     health.set_changed_if(|health| {
+    ///         let previous = health.current;
+    ///         health.current = (health.current + 10).min(health.max);
+    ///         health.current != previous
+    ///     });
+    /// }
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn set_changed_if(&mut self, f: impl FnOnce(&mut Self::Inner) -> bool) -> bool {
+        let changed = f(self.bypass_change_detection());
+        if changed {
+            self.set_changed();
+        }
+        changed
+    }
+}
+
+/// Change detection helpers for optional smart pointers, such as `Option<Res<T>>`.
+///
+/// An absent value is never considered changed, so [`changed`](OptionDetectChanges::changed)
+/// returns `None` when the value is missing. Use
+/// [`is_changed_or_absent`](OptionDetectChanges::is_changed_or_absent) when a missing value
+/// should be handled the same way as a changed one, e.g. to reset some derived state.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_ecs::change_detection::OptionDetectChanges;
+///
+/// #[derive(Resource)]
+/// struct MyResource(u32);
+///
+/// fn my_system(resource: Option<Res<MyResource>>) {
+///     if let Some(resource) = resource.changed() {
+///         println!("My resource was changed to {}!", resource.0);
+///     }
+/// }
+/// ```
+pub trait OptionDetectChanges {
+    /// The change-detecting smart pointer being wrapped.
+    type Item: DetectChanges;
+
+    /// Returns the value if it is present and was added or mutably dereferenced
+    /// since the system last ran, and `None` otherwise.
+    fn changed(&self) -> Option<&Self::Item>;
+
+    /// Returns `true` if the value is absent, or if it was added or mutably dereferenced
+    /// since the system last ran.
+    fn is_changed_or_absent(&self) -> bool;
+}
+
+impl<T: DetectChanges> OptionDetectChanges for Option<T> {
+    type Item = T;
+
+    #[inline]
+    fn changed(&self) -> Option<&T> {
+        self.as_ref().filter(|value| value.is_changed())
+    }
+
+    #[inline]
+    fn is_changed_or_absent(&self) -> bool {
+        self.as_ref().map_or(true, DetectChanges::is_changed)
+    }
+}
+
+/// Change detection for several smart pointers at once, such as `(Res<A>, ResMut<B>)`.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_ecs::change_detection::AnyChanged;
+///
+/// #[derive(Resource)]
+/// struct Width(f32);
+///
+/// #[derive(Resource)]
+/// struct Height(f32);
+///
+/// fn my_system(width: Res<Width>, height: Res<Height>) {
+///     if (width, height).any_changed() {
+///         println!("The layout needs to be recomputed!");
+///     }
+/// }
+/// ```
+pub trait AnyChanged {
+    /// Returns `true` if any of the values were added or mutably dereferenced
+    /// either since the last time the system ran or, if the system never ran,
+    /// since the beginning of the program.
+    fn any_changed(&self) -> bool;
 }
 
+macro_rules! impl_any_changed_tuple {
+    ($(#[$meta:meta])* $($name: ident),*) => {
+        $(#[$meta])*
+        impl<$($name: DetectChanges),*> AnyChanged for ($($name,)*) {
+            #[inline]
+            fn any_changed(&self) -> bool {
+                #[allow(non_snake_case)]
+                let ($($name,)*) = self;
+                false $(|| $name.is_changed())*
+            }
+        }
+    };
+}
+
+all_tuples!(
+    #[doc(fake_variadic)]
+    impl_any_changed_tuple,
+    1,
+    15,
+    T
+);
+
 macro_rules! change_detection_impl {
     ($name:ident < $( $generics:tt ),+ >, $target:ty, $($traits:ident)?) => {
         impl<$($generics),* : ?Sized $(+ $traits)?> DetectChanges for $name<$($generics),*> {
@@ -299,6 +450,48 @@ macro_rules! change_detection_impl {
             }
         }
 
+        impl<$($generics),*: ?Sized $(+ $traits)?> $name<$($generics),*> {
+            /// Returns the [`World`](crate::world::World) change tick seen by the system
+            /// that created this value.
+            #[inline]
+            pub fn this_run(&self) -> Tick {
+                self.ticks.this_run
+            }
+
+            /// Returns the [`World`](crate::world::World) change tick seen by the system
+            /// that created this value, the previous time it ran.
+            ///
+            /// Changes more recent than this tick are reported by [`DetectChanges::is_changed`].
+            #[inline]
+            pub fn last_run(&self) -> Tick {
+                self.ticks.last_run
+            }
+
+            /// Returns `true` if this value was added or mutably dereferenced
+            /// since a specific change tick.
+            #[inline]
+            pub fn has_changed_since(&self, tick: Tick) -> bool {
+                self.ticks.changed.is_newer_than(tick, self.ticks.this_run)
+            }
+
+            /// Returns `true` if this value was added since a specific change tick.
+            #[inline]
+            pub fn has_been_added_since(&self, tick: Tick) -> bool {
+                self.ticks.added.is_newer_than(tick, self.ticks.this_run)
+            }
+
+            /// Returns a clone of the inner value.
+            ///
+            /// This is shorthand for `(*value).clone()`, and does not trigger change detection.
+            #[inline]
+            pub fn cloned(&self) -> $target
+            where
+                $target: Clone,
+            {
+                <$target as Clone>::clone(self.value)
+            }
+        }
+
         impl<$($generics),*: ?Sized $(+ $traits)?> Deref for $name<$($generics),*> {
             type Target = $target;
 
@@ -471,6 +664,33 @@ macro_rules! impl_debug {
     };
 }
 
+macro_rules! impl_partial_eq {
+    ($name:ident < $( $generics:tt ),+ >, $($traits:ident)?) => {
+        impl<$($generics),* : ?Sized $(+ $traits)?> PartialEq<T> for $name<$($generics),*>
+            where T: PartialEq
+        {
+            #[inline]
+            fn eq(&self, other: &T) -> bool {
+                <T as PartialEq>::eq(&*self.value, other)
+            }
+        }
+
+        impl<$($generics),* : ?Sized $(+ $traits)?> PartialEq for $name<$($generics),*>
+            where T: PartialEq
+        {
+            /// Compares the wrapped values, ignoring their change ticks.
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                <T as PartialEq>::eq(&*self.value, &*other.value)
+            }
+        }
+
+        impl<$($generics),* : ?Sized $(+ $traits)?> Eq for $name<$($generics),*>
+            where T: Eq
+        {}
+    };
+}
+
 #[derive(Clone)]
 pub(crate) struct Ticks<'w> {
     pub(crate) added: &'w Tick,
@@ -576,6 +796,17 @@ impl<'w, T: Resource> Res<'w, T> {
     pub fn into_inner(self) -> &'w T {
         self.value
     }
+
+    // Read-only wrappers never implement `DetectChangesMut`. This method turns a direct
+    // `set_changed` call into an unsatisfied bound, so the error carries the trait's
+    // `on_unimplemented` message instead of a plain "method not found".
+    #[doc(hidden)]
+    pub fn set_changed(&mut self)
+    where
+        Self: DetectChangesMut,
+    {
+        <Self as DetectChangesMut>::set_changed(self);
+    }
 }
 
 impl<'w, T: Resource> From<ResMut<'w, T>> for Res<'w, T> {
@@ -615,6 +846,7 @@ where
 }
 change_detection_impl!(Res<'w, T>, T, Resource);
 impl_debug!(Res<'w, T>, Resource);
+impl_partial_eq!(Res<'w, T>, Resource);
 
 /// Unique mutable borrow of a [`Resource`].
 ///
@@ -663,6 +895,7 @@ change_detection_impl!(ResMut<'w, T>, T, Resource);
 change_detection_mut_impl!(ResMut<'w, T>, T, Resource);
 impl_methods!(ResMut<'w, T>, T, Resource);
 impl_debug!(ResMut<'w, T>, Resource);
+impl_partial_eq!(ResMut<'w, T>, Resource);
 
 impl<'w, T: Resource> From<ResMut<'w, T>> for Mut<'w, T> {
     /// Convert this `ResMut` into a `Mut`. This allows keeping the change-detection feature of `Mut`
@@ -700,6 +933,7 @@ change_detection_impl!(NonSendMut<'w, T>, T,);
 change_detection_mut_impl!(NonSendMut<'w, T>, T,);
 impl_methods!(NonSendMut<'w, T>, T,);
 impl_debug!(NonSendMut<'w, T>,);
+impl_partial_eq!(NonSendMut<'w, T>,);
 
 impl<'w, T: 'static> From<NonSendMut<'w, T>> for Mut<'w, T> {
     /// Convert this `NonSendMut` into a `Mut`. This allows keeping the change-detection feature of `Mut`
@@ -751,6 +985,15 @@ impl<'w, T: ?Sized> Ref<'w, T> {
         self.value
     }
 
+    // See `Res::set_changed`.
+    #[doc(hidden)]
+    pub fn set_changed(&mut self)
+    where
+        Self: DetectChangesMut,
+    {
+        <Self as DetectChangesMut>::set_changed(self);
+    }
+
     /// Map `Ref` to a different type using `f`.
     ///
     /// This doesn't do anything else than call `f` on the wrapped value.
@@ -810,6 +1053,7 @@ where
 }
 change_detection_impl!(Ref<'w, T>, T,);
 impl_debug!(Ref<'w, T>,);
+impl_partial_eq!(Ref<'w, T>,);
 
 /// Unique mutable borrow of an entity's component or of a resource.
 ///
@@ -956,6 +1200,7 @@ change_detection_impl!(Mut<'w, T>, T,);
 change_detection_mut_impl!(Mut<'w, T>, T,);
 impl_methods!(Mut<'w, T>, T,);
 impl_debug!(Mut<'w, T>,);
+impl_partial_eq!(Mut<'w, T>,);
 
 /// Unique mutable borrow of resources or an entity's component.
 ///
@@ -973,6 +1218,22 @@ pub struct MutUntyped<'w> {
 }
 
 impl<'w> MutUntyped<'w> {
+    /// Returns the [`World`](crate::world::World) change tick seen by the system
+    /// that created this value.
+    #[inline]
+    pub fn this_run(&self) -> Tick {
+        self.ticks.this_run
+    }
+
+    /// Returns the [`World`](crate::world::World) change tick seen by the system
+    /// that created this value, the previous time it ran.
+    ///
+    /// Changes more recent than this tick are reported by [`DetectChanges::is_changed`].
+    #[inline]
+    pub fn last_run(&self) -> Tick {
+        self.ticks.last_run
+    }
+
     /// Returns the pointer to the value, marking it as changed.
     ///
     /// In order to avoid marking the value as changed, you need to call [`bypass_change_detection`](DetectChangesMut::bypass_change_detection).
@@ -1005,6 +1266,11 @@ impl<'w> MutUntyped<'w> {
         self.ticks.changed.is_newer_than(tick, self.ticks.this_run)
     }
 
+    /// Returns `true` if this value was added since a specific change tick.
+    pub fn has_been_added_since(&self, tick: Tick) -> bool {
+        self.ticks.added.is_newer_than(tick, self.ticks.this_run)
+    }
+
     /// Returns a pointer to the value without taking ownership of this smart pointer, marking it as changed.
     ///
     /// In order to avoid marking the value as changed, you need to call [`bypass_change_detection`](DetectChangesMut::bypass_change_detection).
@@ -1206,14 +1472,14 @@ mod tests {
     use crate::{
         self as bevy_ecs,
         change_detection::{
-            Mut, NonSendMut, Ref, ResMut, TicksMut, CHECK_TICK_THRESHOLD, MAX_CHANGE_AGE,
+            Mut, NonSendMut, Ref, Res, ResMut, TicksMut, CHECK_TICK_THRESHOLD, MAX_CHANGE_AGE,
         },
         component::{Component, ComponentTicks, Tick},
-        system::{IntoSystem, Query, System},
+        system::{IntoSystem, Query, System, SystemChangeTick},
         world::World,
     };
 
-    use super::{DetectChanges, DetectChangesMut, MutUntyped};
+    use super::{AnyChanged, DetectChanges, DetectChangesMut, MutUntyped, OptionDetectChanges};
 
     #[derive(Component, PartialEq)]
     struct C;
@@ -1221,7 +1487,7 @@ mod tests {
     #[derive(Resource)]
     struct R;
 
-    #[derive(Resource, PartialEq)]
+    #[derive(Resource, PartialEq, Clone)]
     struct R2(u8);
 
     impl Deref for R2 {
@@ -1272,6 +1538,28 @@ mod tests {
         assert!(!change_expired_system.run((), &mut world));
     }
 
+    #[test]
+    fn changed_but_not_added() {
+        let mut world = World::new();
+        let existing = world.spawn(C).id();
+        // Components are Changed when first added
+        world.increment_change_tick();
+        // This is required to update world::last_change_tick
+        world.clear_trackers();
+
+        let spawned = world.spawn(C).id();
+        world.get_mut::<C>(existing).unwrap().set_changed();
+
+        let existing = world.entity(existing).get_ref::<C>().unwrap();
+        assert!(existing.is_changed());
+        assert!(existing.changed_but_not_added());
+
+        let spawned = world.entity(spawned).get_ref::<C>().unwrap();
+        assert!(spawned.is_changed());
+        assert!(spawned.is_added());
+        assert!(!spawned.changed_but_not_added());
+    }
+
     #[test]
     fn change_tick_wraparound() {
         let mut world = World::new();
@@ -1403,6 +1691,118 @@ mod tests {
         assert_eq!(4, into_mut.ticks.this_run.get());
     }
 
+    #[test]
+    fn reinserted_resource_is_added() {
+        fn resource_added(resource: Option<Res<R>>) -> bool {
+            resource.is_some_and(|resource| resource.is_added())
+        }
+
+        let mut world = World::new();
+        world.insert_resource(R);
+
+        let mut system = IntoSystem::into_system(resource_added);
+        system.initialize(&mut world);
+
+        assert!(system.run((), &mut world));
+        assert!(!system.run((), &mut world));
+
+        // Remove the resource and insert it again between runs.
+        world.remove_resource::<R>();
+        world.insert_resource(R);
+        assert!(
+            system.run((), &mut world),
+            "A resource absent on the previous run must be reported as added."
+        );
+        assert!(!system.run((), &mut world));
+    }
+
+    #[test]
+    fn option_detect_changes() {
+        let mut world = World::new();
+
+        // None
+        let absent = world.get_resource_ref::<R2>();
+        assert!(absent.changed().is_none());
+        assert!(absent.is_changed_or_absent());
+
+        // Some(changed)
+        world.insert_resource(R2(0));
+        let changed = world.get_resource_ref::<R2>();
+        assert!(changed.changed().is_some());
+        assert!(changed.is_changed_or_absent());
+
+        // Some(unchanged)
+        world.increment_change_tick();
+        world.clear_trackers();
+        let unchanged = world.get_resource_ref::<R2>();
+        assert!(unchanged.changed().is_none());
+        assert!(!unchanged.is_changed_or_absent());
+    }
+
+    #[test]
+    fn ticks_match_system_change_tick() {
+        fn ticks_match(resource: Res<R>, system_ticks: SystemChangeTick) -> bool {
+            resource.last_run() == system_ticks.last_run()
+                && resource.this_run() == system_ticks.this_run()
+        }
+
+        let mut world = World::new();
+        world.insert_resource(R);
+
+        let mut system = IntoSystem::into_system(ticks_match);
+        system.initialize(&mut world);
+
+        assert!(system.run((), &mut world));
+        world.increment_change_tick();
+        assert!(system.run((), &mut world));
+    }
+
+    #[test]
+    fn changed_since_tick() {
+        let mut world = World::new();
+        world.insert_resource(R2(0));
+        let inserted = world.change_tick();
+
+        world.increment_change_tick();
+        let saved = world.change_tick();
+        world.increment_change_tick();
+
+        let r = world.resource_ref::<R2>();
+        assert!(r.has_been_added_since(Tick::new(0)));
+        assert!(!r.has_been_added_since(inserted));
+        assert!(!r.has_changed_since(saved));
+
+        world.resource_mut::<R2>().0 = 1;
+
+        let r = world.resource_ref::<R2>();
+        assert!(r.has_changed_since(saved));
+        assert!(!r.has_been_added_since(saved));
+    }
+
+    #[test]
+    fn any_changed() {
+        fn any_changed(r: Res<R>, r2: Res<R2>) -> bool {
+            (r, r2).any_changed()
+        }
+
+        let mut world = World::new();
+        world.insert_resource(R);
+        world.insert_resource(R2(0));
+
+        let mut system = IntoSystem::into_system(any_changed);
+        system.initialize(&mut world);
+
+        assert!(system.run((), &mut world));
+        assert!(!system.run((), &mut world));
+
+        world.resource_mut::<R2>().0 = 1;
+        assert!(system.run((), &mut world));
+        assert!(!system.run((), &mut world));
+
+        world.resource_mut::<R>().set_changed();
+        assert!(system.run((), &mut world));
+    }
+
     #[test]
     fn map_mut() {
         use super::*;
@@ -1444,6 +1844,32 @@ mod tests {
         assert!(component_ticks.is_changed(last_run, this_run));
     }
 
+    #[test]
+    fn reborrow_mut() {
+        fn bump(mut value: Mut<R2>) {
+            value.0 += 1;
+        }
+
+        let mut world = World::new();
+
+        world.insert_resource(R2(0));
+        // Resources are Changed when first added
+        world.increment_change_tick();
+        // This is required to update world::last_change_tick
+        world.clear_trackers();
+
+        let mut r = world.resource_mut::<R2>();
+        assert!(!r.is_changed(), "Resource must begin unchanged.");
+
+        bump(r.reborrow());
+        bump(r.reborrow());
+        assert_eq!(r.0, 2);
+        assert!(
+            r.is_changed(),
+            "Writes through a reborrow must mark the original as changed."
+        );
+    }
+
     #[test]
     fn set_if_neq() {
         let mut world = World::new();
@@ -1470,6 +1896,131 @@ mod tests {
         );
     }
 
+    #[test]
+    fn bypass_change_detection() {
+        let mut world = World::new();
+
+        world.insert_resource(R2(0));
+        // Resources are Changed when first added
+        world.increment_change_tick();
+        // This is required to update world::last_change_tick
+        world.clear_trackers();
+
+        let mut r = world.resource_mut::<R2>();
+        assert!(!r.is_changed(), "Resource must begin unchanged.");
+
+        r.bypass_change_detection().0 = 3;
+        assert_eq!(r.0, 3);
+        assert!(
+            !r.is_changed(),
+            "Writing through bypass_change_detection must not mark the resource as changed."
+        );
+    }
+
+    #[test]
+    fn partial_eq_delegates_to_value() {
+        let mut world = World::new();
+        world.insert_resource(R2(5));
+
+        let r = world.resource_ref::<R2>();
+        assert!(r == R2(5));
+        assert!(r != R2(3));
+
+        let other = world.resource_ref::<R2>();
+        assert!(r == other);
+        assert!(r == *other);
+
+        // Resources are Changed when first added
+        world.increment_change_tick();
+        // This is required to update world::last_change_tick
+        world.clear_trackers();
+
+        let r = world.resource_mut::<R2>();
+        assert!(r == R2(5));
+        assert!(
+            !r.is_changed(),
+            "Comparing must not mark the resource as changed."
+        );
+    }
+
+    #[test]
+    fn partial_eq_between_wrappers() {
+        #[derive(Component, PartialEq, Eq)]
+        struct Value(u8);
+
+        fn is_eq<T: Eq>(_: &T) {}
+
+        let mut world = World::new();
+        let a = world.spawn(Value(1)).id();
+        world.increment_change_tick();
+        let b = world.spawn(Value(1)).id();
+        let c = world.spawn(Value(2)).id();
+
+        let a = world.entity(a).get_ref::<Value>().unwrap();
+        let b = world.entity(b).get_ref::<Value>().unwrap();
+        let c = world.entity(c).get_ref::<Value>().unwrap();
+        // Only the values are compared, not when they were added or changed
+        assert!(a == b);
+        assert!(a != c);
+        is_eq(&a);
+    }
+
+    #[test]
+    fn cloned() {
+        let mut world = World::new();
+        world.insert_resource(R2(5));
+        // Resources are Changed when first added
+        world.increment_change_tick();
+        // This is required to update world::last_change_tick
+        world.clear_trackers();
+
+        let r = world.resource_ref::<R2>();
+        let value: R2 = r.cloned();
+        assert!(value == R2(5));
+
+        let r = world.resource_mut::<R2>();
+        assert!(r.cloned() == R2(5));
+        assert!(
+            !r.is_changed(),
+            "Cloning must not mark the resource as changed."
+        );
+    }
+
+    #[test]
+    fn set_changed_if() {
+        let mut world = World::new();
+
+        world.insert_resource(R2(0));
+        // Resources are Changed when first added
+        world.increment_change_tick();
+        // This is required to update world::last_change_tick
+        world.clear_trackers();
+
+        let mut r = world.resource_mut::<R2>();
+        assert!(!r.is_changed(), "Resource must begin unchanged.");
+
+        assert!(!r.set_changed_if(|r| {
+            let previous = r.0;
+            r.0 = 0;
+            r.0 != previous
+        }));
+        assert!(
+            !r.is_changed(),
+            "Resource must not be changed after writing the same value."
+        );
+
+        assert!(r.set_changed_if(|r| {
+            let previous = r.0;
+            r.0 = 3;
+            r.0 != previous
+        }));
+        assert_eq!(r.0, 3);
+        assert!(
+            r.is_changed(),
+            "Resource must be changed after writing a different value."
+        );
+    }
+
     #[test]
     fn as_deref_mut() {
         let mut world = World::new();
@@ -899,6 +899,87 @@ mod tests {
         assert_eq!(*world.resource::<SystemRan>(), SystemRan::Yes);
     }
 
+    #[test]
+    fn non_send_into_inner() {
+        let mut world = World::default();
+
+        world.insert_resource(SystemRan::No);
+        struct NotSend(alloc::rc::Rc<i32>);
+        world.insert_non_send_resource(NotSend(alloc::rc::Rc::new(7)));
+
+        fn sys(op: NonSend<NotSend>, mut system_ran: ResMut<SystemRan>) {
+            let inner: &NotSend = {
+                let wrapper = op;
+                wrapper.into_inner()
+            };
+            assert_eq!(*inner.0, 7);
+            *system_ran = SystemRan::Yes;
+        }
+
+        run_system(&mut world, sys);
+        assert_eq!(*world.resource::<SystemRan>(), SystemRan::Yes);
+    }
+
+    #[test]
+    fn non_send_partial_eq() {
+        let mut world = World::default();
+
+        world.insert_resource(SystemRan::No);
+        #[derive(PartialEq, Eq)]
+        struct NotSend(alloc::rc::Rc<i32>);
+        world.insert_non_send_resource(NotSend(alloc::rc::Rc::new(7)));
+
+        fn is_eq<T: Eq>(_: &T) {}
+
+        fn sys(a: NonSend<NotSend>, b: NonSend<NotSend>, mut system_ran: ResMut<SystemRan>) {
+            assert!(a == NotSend(alloc::rc::Rc::new(7)));
+            assert!(a != NotSend(alloc::rc::Rc::new(8)));
+            assert!(a == b);
+            is_eq(&a);
+            *system_ran = SystemRan::Yes;
+        }
+
+        run_system(&mut world, sys);
+        assert_eq!(*world.resource::<SystemRan>(), SystemRan::Yes);
+    }
+
+    #[test]
+    fn non_send_mut_is_added() {
+        #[allow(dead_code)]
+        struct NotSend(alloc::rc::Rc<i32>);
+
+        fn is_added(value: Option<NonSendMut<NotSend>>) -> bool {
+            value.is_some_and(|value| value.is_added())
+        }
+
+        let mut world = World::default();
+        let mut system = IntoSystem::into_system(is_added);
+        system.initialize(&mut world);
+        assert!(!system.run((), &mut world));
+
+        world.insert_non_send_resource(NotSend(alloc::rc::Rc::new(0)));
+        assert!(system.run((), &mut world));
+        assert!(!system.run((), &mut world));
+
+        // Inserted by an earlier system in the same schedule run
+        fn insert(world: &mut World) {
+            world.insert_non_send_resource(NotSend(alloc::rc::Rc::new(1)));
+        }
+
+        fn check(value: NonSendMut<NotSend>, mut system_ran: ResMut<SystemRan>) {
+            if value.is_added() {
+                *system_ran = SystemRan::Yes;
+            }
+        }
+
+        let mut world = World::default();
+        world.insert_resource(SystemRan::No);
+        let mut schedule = Schedule::default();
+        schedule.add_systems((insert, check).chain());
+        schedule.run(&mut world);
+        assert_eq!(*world.resource::<SystemRan>(), SystemRan::Yes);
+    }
+
     #[test]
     fn non_send_system() {
         let mut world = World::default();
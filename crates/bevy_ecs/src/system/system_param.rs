@@ -2,7 +2,7 @@ pub use crate::change_detection::{NonSendMut, Res, ResMut};
 use crate::{
     archetype::{Archetype, Archetypes},
     bundle::Bundles,
-    change_detection::{Ticks, TicksMut},
+    change_detection::{DetectChangesMut, Ticks, TicksMut},
     component::{ComponentId, ComponentTicks, Components, Tick},
     entity::Entities,
     query::{
@@ -1198,6 +1198,29 @@ where
     }
 }
 
+impl<'w, T> PartialEq<T> for NonSend<'w, T>
+where
+    T: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &T) -> bool {
+        <T as PartialEq>::eq(self.value, other)
+    }
+}
+
+impl<'w, T> PartialEq for NonSend<'w, T>
+where
+    T: PartialEq,
+{
+    /// Compares the wrapped values, ignoring their change ticks.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        <T as PartialEq>::eq(self.value, other.value)
+    }
+}
+
+impl<'w, T> Eq for NonSend<'w, T> where T: Eq {}
+
 impl<'w, T: 'static> NonSend<'w, T> {
     /// Returns `true` if the resource was added after the system last ran.
     pub fn is_added(&self) -> bool {
@@ -1209,11 +1232,56 @@ impl<'w, T: 'static> NonSend<'w, T> {
         self.ticks.is_changed(self.last_run, self.this_run)
     }
 
+    /// Returns the change tick recording the time this resource was most recently changed.
+    ///
+    /// This is the non-[`Send`] equivalent of [`DetectChanges::last_changed`](crate::change_detection::DetectChanges::last_changed),
+    /// and is only meaningful when compared against other ticks from the same [`World`].
+    pub fn last_changed(&self) -> Tick {
+        self.ticks.changed
+    }
+
+    /// Returns the [`World`] change tick seen by the system that created this value.
+    pub fn this_run(&self) -> Tick {
+        self.this_run
+    }
+
+    /// Returns the [`World`] change tick seen by the system that created this value,
+    /// the previous time it ran.
+    pub fn last_run(&self) -> Tick {
+        self.last_run
+    }
+
+    /// Returns `true` if the resource was added or mutably dereferenced since a specific change tick.
+    pub fn has_changed_since(&self, tick: Tick) -> bool {
+        self.ticks.changed.is_newer_than(tick, self.this_run)
+    }
+
+    /// Returns `true` if the resource was added since a specific change tick.
+    pub fn has_been_added_since(&self, tick: Tick) -> bool {
+        self.ticks.added.is_newer_than(tick, self.this_run)
+    }
+
     /// The location that last caused this to change.
     #[cfg(feature = "track_change_detection")]
     pub fn changed_by(&self) -> &'static Location<'static> {
         self.changed_by
     }
+
+    /// Due to lifetime limitations of the `Deref` trait, this method can be used to obtain a
+    /// reference of the resource with a lifetime bound to `'w` instead of the lifetime of the
+    /// struct itself.
+    pub fn into_inner(self) -> &'w T {
+        self.value
+    }
+
+    // See `Res::set_changed`.
+    #[doc(hidden)]
+    pub fn set_changed(&mut self)
+    where
+        Self: DetectChangesMut,
+    {
+        <Self as DetectChangesMut>::set_changed(self);
+    }
 }
 
 impl<'w, T> Deref for NonSend<'w, T> {
@@ -85,6 +85,12 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> proc_macro2::
             fn iter_fields(&self) -> #bevy_reflect_path::TupleStructFieldIter {
                 #bevy_reflect_path::TupleStructFieldIter::new(self)
             }
+            #[inline]
+            fn iter_fields_mut(&mut self) -> #bevy_reflect_path::TupleStructFieldIterMut {
+                #bevy_reflect_path::TupleStructFieldIterMut::new(::std::vec![
+                    #(#fields_mut as &mut dyn #bevy_reflect_path::PartialReflect,)*
+                ])
+            }
 
             fn clone_dynamic(&self) -> #bevy_reflect_path::DynamicTupleStruct {
                 let mut dynamic: #bevy_reflect_path::DynamicTupleStruct = #FQDefault::default();
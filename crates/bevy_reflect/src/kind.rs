@@ -309,6 +309,30 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn should_cast_tuple() {
+        let mut value = (1_i32, 2_i32);
+        assert!(value.reflect_ref().as_tuple().is_ok());
+        assert!(value.reflect_mut().as_tuple().is_ok());
+        assert!(value.reflect_ref().as_tuple_struct().is_err());
+
+        let mut value = 1_i32;
+        assert!(matches!(
+            value.reflect_ref().as_tuple(),
+            Err(ReflectKindMismatchError {
+                expected: ReflectKind::Tuple,
+                received: ReflectKind::Opaque
+            })
+        ));
+        assert!(matches!(
+            value.reflect_mut().as_tuple_struct(),
+            Err(ReflectKindMismatchError {
+                expected: ReflectKind::TupleStruct,
+                received: ReflectKind::Opaque
+            })
+        ));
+    }
+
     #[test]
     fn should_cast_owned() {
         let value = Box::new(Some(123));
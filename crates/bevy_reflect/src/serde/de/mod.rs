@@ -285,6 +285,48 @@ mod tests {
         assert_eq!(expected, output);
     }
 
+    #[test]
+    fn should_deserialize_tuple_struct_as_dynamic() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Foo(i32, f32);
+
+        let mut registry = get_registry();
+        registry.register::<Foo>();
+        let registration = registry.get(TypeId::of::<Foo>()).unwrap();
+
+        let reflect_deserializer = TypedReflectDeserializer::new_internal(registration, &registry);
+        let mut ron_deserializer = ron::de::Deserializer::from_str("(1, 2.0)").unwrap();
+        let dynamic_output = reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .unwrap();
+
+        assert!(dynamic_output.is_dynamic());
+        let dynamic = dynamic_output
+            .reflect_ref()
+            .as_tuple_struct()
+            .expect("output should be a tuple struct");
+        assert_eq!(2, dynamic.field_len());
+        assert_eq!(
+            Some(&1),
+            dynamic.field(0).unwrap().try_downcast_ref::<i32>()
+        );
+        assert_eq!(
+            Some(&2.0),
+            dynamic.field(1).unwrap().try_downcast_ref::<f32>()
+        );
+        assert_eq!(
+            Some(Foo(1, 2.0)),
+            Foo::from_reflect(dynamic.as_partial_reflect())
+        );
+
+        // Missing fields are reported as an error rather than silently defaulted
+        let reflect_deserializer = TypedReflectDeserializer::new_internal(registration, &registry);
+        let mut ron_deserializer = ron::de::Deserializer::from_str("(1)").unwrap();
+        assert!(reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .is_err());
+    }
+
     #[test]
     fn should_deserialize_option() {
         #[derive(Reflect, Debug, PartialEq)]
@@ -12,7 +12,8 @@ mod tests {
         self as bevy_reflect,
         serde::{ReflectDeserializer, ReflectSerializer},
         type_registry::TypeRegistry,
-        DynamicStruct, DynamicTupleStruct, FromReflect, PartialReflect, Reflect, Struct,
+        DynamicStruct, DynamicTuple, DynamicTupleStruct, FromReflect, PartialReflect, Reflect,
+        Struct, Tuple,
     };
     use serde::de::DeserializeSeed;
 
@@ -183,4 +184,28 @@ mod tests {
             .reflect_partial_eq(result.as_partial_reflect())
             .unwrap());
     }
+
+    #[test]
+    fn should_roundtrip_proxied_dynamic_tuple() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<(i32, String)>();
+
+        let value: DynamicTuple = (123_i32, String::from("hello")).clone_dynamic();
+
+        let serializer = ReflectSerializer::new(&value, &registry);
+
+        let expected = r#"{"(i32, alloc::string::String)":(123,"hello")}"#;
+        let result = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(expected, result);
+
+        let mut deserializer = ron::de::Deserializer::from_str(&result).unwrap();
+        let reflect_deserializer = ReflectDeserializer::new(&registry);
+
+        let expected = value.clone_value();
+        let result = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+
+        assert!(expected
+            .reflect_partial_eq(result.as_partial_reflect())
+            .unwrap());
+    }
 }
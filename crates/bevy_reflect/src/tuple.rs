@@ -2,16 +2,21 @@ use bevy_reflect_derive::impl_type_path;
 use bevy_utils::all_tuples;
 
 use crate::{
-    self as bevy_reflect, type_info::impl_type_methods, utility::GenericTypePathCell, ApplyError,
-    FromReflect, GetTypeRegistration, MaybeTyped, PartialReflect, Reflect, ReflectKind, ReflectMut,
-    ReflectOwned, ReflectRef, Type, TypeInfo, TypePath, TypeRegistration, TypeRegistry, Typed,
-    UnnamedField,
+    self as bevy_reflect,
+    type_info::impl_type_methods,
+    utility::{reflect_hasher, GenericTypePathCell},
+    ApplyError, FromReflect, GetTypeRegistration, MaybeTyped, PartialReflect, Reflect, ReflectKind,
+    ReflectMut, ReflectOwned, ReflectRef, Type, TypeInfo, TypePath, TypeRegistration, TypeRegistry,
+    Typed, UnnamedField,
 };
 use core::{
-    any::Any,
+    any::{Any, TypeId},
     fmt::{Debug, Formatter},
+    hash::{Hash, Hasher},
+    ops::{Index, IndexMut},
     slice::Iter,
 };
+use thiserror::Error;
 
 /// A trait used to power [tuple-like] operations via [reflection].
 ///
@@ -50,10 +55,20 @@ pub trait Tuple: PartialReflect {
     /// Returns an iterator over the values of the tuple's fields.
     fn iter_fields(&self) -> TupleFieldIter;
 
+    /// Returns an iterator over mutable references to the values of the tuple's fields.
+    fn iter_fields_mut(&mut self) -> TupleFieldIterMut;
+
     /// Drain the fields of this tuple to get a vector of owned values.
     fn drain(self: Box<Self>) -> Vec<Box<dyn PartialReflect>>;
 
-    /// Clones the struct into a [`DynamicTuple`].
+    /// Clones the tuple into a [`DynamicTuple`].
+    ///
+    /// Each field is cloned with [`PartialReflect::clone_value`], so fields that are themselves
+    /// structs, tuples, lists, etc. become dynamic types too, while opaque values keep their
+    /// concrete type. The returned tuple represents the original type, so it can be turned back
+    /// into a concrete value with [`FromReflect::from_reflect`](crate::FromReflect::from_reflect).
+    ///
+    /// If a concrete copy is needed and the tuple implements [`Clone`], prefer cloning it directly.
     fn clone_dynamic(&self) -> DynamicTuple;
 }
 
@@ -89,6 +104,33 @@ impl<'a> Iterator for TupleFieldIter<'a> {
 
 impl<'a> ExactSizeIterator for TupleFieldIter<'a> {}
 
+/// A mutable iterator over the field values of a tuple.
+pub struct TupleFieldIterMut<'a> {
+    pub(crate) fields: alloc::vec::IntoIter<&'a mut dyn PartialReflect>,
+}
+
+impl<'a> TupleFieldIterMut<'a> {
+    pub fn new(fields: Vec<&'a mut dyn PartialReflect>) -> Self {
+        TupleFieldIterMut {
+            fields: fields.into_iter(),
+        }
+    }
+}
+
+impl<'a> Iterator for TupleFieldIterMut<'a> {
+    type Item = &'a mut dyn PartialReflect;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.fields.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.fields.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for TupleFieldIterMut<'a> {}
+
 /// A convenience trait which combines fetching and downcasting of tuple
 /// fields.
 ///
@@ -112,6 +154,27 @@ pub trait GetTupleField {
     /// Returns a mutable reference to the value of the field with index
     /// `index`, downcast to `T`.
     fn get_field_mut<T: Reflect>(&mut self, index: usize) -> Option<&mut T>;
+
+    /// Returns a reference to the value of the field with index `index`,
+    /// downcast to `T`.
+    ///
+    /// Unlike [`get_field`](GetTupleField::get_field), this reports whether the field
+    /// was missing or simply of a different type.
+    fn try_get_field<T: Reflect + TypePath>(&self, index: usize) -> Result<&T, GetTupleFieldError>;
+
+    /// Returns a reference to the value of the field with index `index`,
+    /// if its concrete type has the given [`TypeId`].
+    ///
+    /// This is the runtime counterpart to [`get_field`](GetTupleField::get_field)
+    /// for code that only knows the type it is looking for dynamically.
+    fn get_field_by_type_id(&self, index: usize, type_id: TypeId) -> Option<&dyn Reflect>;
+
+    /// Returns `true` if the tuple has exactly `expected` fields.
+    ///
+    /// The expected arity of a tuple type can be read from its [`TupleInfo`]
+    /// without constructing it, which makes this a cheap check to run before
+    /// attempting [`FromReflect`].
+    fn arity_matches(&self, expected: usize) -> bool;
 }
 
 impl<S: Tuple> GetTupleField for S {
@@ -124,6 +187,20 @@ impl<S: Tuple> GetTupleField for S {
         self.field_mut(index)
             .and_then(|value| value.try_downcast_mut::<T>())
     }
+
+    fn try_get_field<T: Reflect + TypePath>(&self, index: usize) -> Result<&T, GetTupleFieldError> {
+        try_downcast_tuple_field(self, index)
+    }
+
+    fn get_field_by_type_id(&self, index: usize, type_id: TypeId) -> Option<&dyn Reflect> {
+        self.field(index)
+            .and_then(|value| value.try_as_reflect())
+            .filter(|value| value.as_any().type_id() == type_id)
+    }
+
+    fn arity_matches(&self, expected: usize) -> bool {
+        self.field_len() == expected
+    }
 }
 
 impl GetTupleField for dyn Tuple {
@@ -136,6 +213,51 @@ impl GetTupleField for dyn Tuple {
         self.field_mut(index)
             .and_then(|value| value.try_downcast_mut::<T>())
     }
+
+    fn try_get_field<T: Reflect + TypePath>(&self, index: usize) -> Result<&T, GetTupleFieldError> {
+        try_downcast_tuple_field(self, index)
+    }
+
+    fn get_field_by_type_id(&self, index: usize, type_id: TypeId) -> Option<&dyn Reflect> {
+        self.field(index)
+            .and_then(|value| value.try_as_reflect())
+            .filter(|value| value.as_any().type_id() == type_id)
+    }
+
+    fn arity_matches(&self, expected: usize) -> bool {
+        self.field_len() == expected
+    }
+}
+
+fn try_downcast_tuple_field<T: Reflect + TypePath>(
+    tuple: &(impl Tuple + ?Sized),
+    index: usize,
+) -> Result<&T, GetTupleFieldError> {
+    let field = tuple
+        .field(index)
+        .ok_or_else(|| GetTupleFieldError::IndexOutOfBounds {
+            index,
+            field_len: tuple.field_len(),
+        })?;
+
+    field
+        .try_downcast_ref::<T>()
+        .ok_or_else(|| GetTupleFieldError::MismatchedTypes {
+            expected: T::type_path().into(),
+            found: field.reflect_type_path().into(),
+        })
+}
+
+/// A enumeration of all error outcomes that might happen when running [`GetTupleField::try_get_field`].
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum GetTupleFieldError {
+    #[error("index {index} is out of bounds for a tuple with {field_len} fields")]
+    /// The given index was greater than or equal to the number of fields in the tuple.
+    IndexOutOfBounds { index: usize, field_len: usize },
+
+    #[error("expected a field of type `{expected}` but found `{found}`")]
+    /// The field exists but could not be downcast to the requested type.
+    MismatchedTypes { expected: Box<str>, found: Box<str> },
 }
 
 /// A container for compile-time tuple info.
@@ -193,7 +315,10 @@ impl TupleInfo {
 }
 
 /// A tuple which allows fields to be added at runtime.
-#[derive(Default, Debug)]
+///
+/// Applying a longer tuple to a `DynamicTuple` appends clones of the extra fields,
+/// whereas concrete tuples require both sides to have the same number of fields.
+#[derive(Default)]
 pub struct DynamicTuple {
     represented_type: Option<&'static TypeInfo>,
     fields: Vec<Box<dyn PartialReflect>>,
@@ -226,9 +351,86 @@ impl DynamicTuple {
 
     /// Appends a typed element with value `value` to the tuple.
     pub fn insert<T: PartialReflect>(&mut self, value: T) {
-        self.represented_type = None;
         self.insert_boxed(Box::new(value));
     }
+
+    /// Appends a placeholder element to the tuple, to be replaced later with [`set_field`].
+    ///
+    /// The placeholder is a unit value `()`, which keeps the indices of the following elements
+    /// stable while the tuple is built up incrementally.
+    /// Until every placeholder has been replaced, the tuple will generally not convert to the
+    /// intended concrete type via [`FromReflect`].
+    ///
+    /// [`set_field`]: Self::set_field
+    pub fn insert_placeholder(&mut self) {
+        self.insert(());
+    }
+
+    /// Removes the element at `index` from the tuple, shifting all elements after it down.
+    ///
+    /// Returns [`None`] if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Option<Box<dyn PartialReflect>> {
+        if index >= self.fields.len() {
+            return None;
+        }
+
+        self.represented_type = None;
+        Some(self.fields.remove(index))
+    }
+
+    /// Replaces the element at `index` with `value`, returning the previous element.
+    ///
+    /// The represented type is only cleared if `value` represents a different type than
+    /// the element it replaces, or if either of them has no represented type.
+    ///
+    /// Returns [`None`] and drops `value` if `index` is out of bounds.
+    pub fn set_field(
+        &mut self,
+        index: usize,
+        value: Box<dyn PartialReflect>,
+    ) -> Option<Box<dyn PartialReflect>> {
+        let field = self.fields.get_mut(index)?;
+
+        if !represent_same_type(&**field, &*value) {
+            self.represented_type = None;
+        }
+        Some(core::mem::replace(field, value))
+    }
+
+    /// Swaps the elements at indices `a` and `b`.
+    ///
+    /// The represented type is only cleared if the two elements represent different types,
+    /// or if either of them has no represented type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        if !represent_same_type(&*self.fields[a], &*self.fields[b]) {
+            self.represented_type = None;
+        }
+        self.fields.swap(a, b);
+    }
+
+    /// Removes all elements from the tuple and returns them, leaving it empty.
+    ///
+    /// Unlike [`Tuple::drain`] or [`IntoIterator`], this does not consume the tuple,
+    /// so it can be reused afterwards. The represented type is cleared.
+    pub fn drain_fields(&mut self) -> Vec<Box<dyn PartialReflect>> {
+        self.represented_type = None;
+        core::mem::take(&mut self.fields)
+    }
+}
+
+/// Returns `true` if `a` and `b` both have a represented type, and it is the same one.
+///
+/// Dynamic values all share their container's type path, so their represented type info
+/// has to be compared instead.
+fn represent_same_type(a: &dyn PartialReflect, b: &dyn PartialReflect) -> bool {
+    match (a.get_represented_type_info(), b.get_represented_type_info()) {
+        (Some(a), Some(b)) => a.type_id() == b.type_id(),
+        _ => false,
+    }
 }
 
 impl Tuple for DynamicTuple {
@@ -255,6 +457,16 @@ impl Tuple for DynamicTuple {
         }
     }
 
+    #[inline]
+    fn iter_fields_mut(&mut self) -> TupleFieldIterMut {
+        TupleFieldIterMut::new(
+            self.fields
+                .iter_mut()
+                .map(|field| &mut **field as &mut dyn PartialReflect)
+                .collect(),
+        )
+    }
+
     #[inline]
     fn drain(self: Box<Self>) -> Vec<Box<dyn PartialReflect>> {
         self.fields
@@ -305,7 +517,9 @@ impl PartialReflect for DynamicTuple {
     }
 
     fn apply(&mut self, value: &dyn PartialReflect) {
-        tuple_apply(self, value);
+        if let Err(err) = self.try_apply(value) {
+            panic!("{err}");
+        }
     }
 
     #[inline]
@@ -334,7 +548,30 @@ impl PartialReflect for DynamicTuple {
     }
 
     fn try_apply(&mut self, value: &dyn PartialReflect) -> Result<(), ApplyError> {
-        tuple_try_apply(self, value)
+        let tuple = value.reflect_ref().as_tuple()?;
+
+        if tuple.field_len() < self.field_len() {
+            return Err(ApplyError::DifferentSize {
+                from_size: tuple.field_len(),
+                to_size: self.field_len(),
+            });
+        }
+
+        let len = self.fields.len();
+        for (i, value) in tuple.iter_fields().enumerate() {
+            if i < len {
+                self.fields[i].try_apply(value)?;
+            } else {
+                self.insert_boxed(value.clone_value());
+            }
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn reflect_hash(&self) -> Option<u64> {
+        tuple_hash(self)
     }
 
     fn reflect_partial_eq(&self, value: &dyn PartialReflect) -> Option<bool> {
@@ -355,6 +592,42 @@ impl PartialReflect for DynamicTuple {
 
 impl_type_path!((in bevy_reflect) DynamicTuple);
 
+impl Debug for DynamicTuple {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.debug(f)
+    }
+}
+
+impl Clone for DynamicTuple {
+    fn clone(&self) -> Self {
+        self.clone_dynamic()
+    }
+}
+
+/// Returns the field at `index`.
+///
+/// # Panics
+///
+/// Panics if `index` is out of bounds.
+impl Index<usize> for DynamicTuple {
+    type Output = dyn PartialReflect;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &*self.fields[index]
+    }
+}
+
+/// Returns the field at `index` mutably.
+///
+/// # Panics
+///
+/// Panics if `index` is out of bounds.
+impl IndexMut<usize> for DynamicTuple {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut *self.fields[index]
+    }
+}
+
 impl FromIterator<Box<dyn PartialReflect>> for DynamicTuple {
     fn from_iter<I: IntoIterator<Item = Box<dyn PartialReflect>>>(fields: I) -> Self {
         Self {
@@ -382,11 +655,25 @@ impl<'a> IntoIterator for &'a DynamicTuple {
     }
 }
 
+/// Returns the `u64` hash of the given [tuple](Tuple).
+///
+/// Returns [`None`] if any of the tuple's fields does not support hashing.
+#[inline]
+pub fn tuple_hash<T: Tuple + ?Sized>(tuple: &T) -> Option<u64> {
+    let mut hasher = reflect_hasher();
+    Any::type_id(tuple).hash(&mut hasher);
+    tuple.field_len().hash(&mut hasher);
+    for value in tuple.iter_fields() {
+        hasher.write_u64(value.reflect_hash()?);
+    }
+    Some(hasher.finish())
+}
+
 /// Applies the elements of `b` to the corresponding elements of `a`.
 ///
 /// # Panics
 ///
-/// This function panics if `b` is not a tuple.
+/// This function panics if `b` is not a tuple or if the two tuples have differing lengths.
 #[inline]
 pub fn tuple_apply<T: Tuple>(a: &mut T, b: &dyn PartialReflect) {
     if let Err(err) = tuple_try_apply(a, b) {
@@ -399,12 +686,20 @@ pub fn tuple_apply<T: Tuple>(a: &mut T, b: &dyn PartialReflect) {
 ///
 /// # Errors
 ///
-/// This function returns an [`ApplyError::MismatchedKinds`] if `b` is not a tuple or if
-/// applying elements to each other fails.
+/// * Returns an [`ApplyError::MismatchedKinds`] if `b` is not a tuple.
+/// * Returns an [`ApplyError::DifferentSize`] if the two tuples have differing lengths.
+/// * Returns any error that is generated while applying elements to each other.
 #[inline]
 pub fn tuple_try_apply<T: Tuple>(a: &mut T, b: &dyn PartialReflect) -> Result<(), ApplyError> {
     let tuple = b.reflect_ref().as_tuple()?;
 
+    if a.field_len() != tuple.field_len() {
+        return Err(ApplyError::DifferentSize {
+            from_size: tuple.field_len(),
+            to_size: a.field_len(),
+        });
+    }
+
     for (i, value) in tuple.iter_fields().enumerate() {
         if let Some(v) = a.field_mut(i) {
             v.try_apply(value)?;
@@ -422,6 +717,9 @@ pub fn tuple_try_apply<T: Tuple>(a: &mut T, b: &dyn PartialReflect) -> Result<()
 /// - [`PartialReflect::reflect_partial_eq`] returns `Some(true)` for pairwise elements of `a` and `b`.
 ///
 /// Returns [`None`] if the comparison couldn't even be performed.
+///
+/// Floating-point fields follow IEEE semantics, so a tuple containing `NaN` is not equal
+/// to itself. Use [`tuple_partial_eq_total`] to compare floats by their bits instead.
 #[inline]
 pub fn tuple_partial_eq<T: Tuple + ?Sized>(a: &T, b: &dyn PartialReflect) -> Option<bool> {
     let ReflectRef::Tuple(b) = b.reflect_ref() else {
@@ -442,6 +740,91 @@ pub fn tuple_partial_eq<T: Tuple + ?Sized>(a: &T, b: &dyn PartialReflect) -> Opt
     Some(true)
 }
 
+/// Compares a [`Tuple`] with a [`PartialReflect`] value, treating bit-identical floats as equal.
+///
+/// This behaves like [`tuple_partial_eq`], except that `f32` and `f64` fields, including those
+/// of nested tuples and tuple structs, are compared by their bits rather than their IEEE value.
+/// As a result `NaN` equals itself, which makes the comparison reflexive and suitable for
+/// deduplication or caching, while `0.0` and `-0.0` are no longer considered equal.
+///
+/// Returns [`None`] if the comparison couldn't even be performed.
+///
+/// # Example
+/// ```
+/// use bevy_reflect::{tuple_partial_eq, tuple_partial_eq_total};
+///
+/// let value = (1_i32, f32::NAN);
+/// assert_eq!(Some(false), tuple_partial_eq(&value, &value));
+/// assert_eq!(Some(true), tuple_partial_eq_total(&value, &value));
+/// ```
+pub fn tuple_partial_eq_total<T: Tuple + ?Sized>(a: &T, b: &dyn PartialReflect) -> Option<bool> {
+    let ReflectRef::Tuple(b) = b.reflect_ref() else {
+        return Some(false);
+    };
+
+    if a.field_len() != b.field_len() {
+        return Some(false);
+    }
+
+    for (a_field, b_field) in a.iter_fields().zip(b.iter_fields()) {
+        let eq_result = field_partial_eq_total(a_field, b_field);
+        if let failed @ (Some(false) | None) = eq_result {
+            return failed;
+        }
+    }
+
+    Some(true)
+}
+
+/// Compares two fields for [`tuple_partial_eq_total`] and [`tuple_struct_partial_eq_total`].
+///
+/// [`tuple_struct_partial_eq_total`]: crate::tuple_struct_partial_eq_total
+pub(crate) fn field_partial_eq_total(
+    a: &dyn PartialReflect,
+    b: &dyn PartialReflect,
+) -> Option<bool> {
+    if let (Some(a), Some(b)) = (a.try_downcast_ref::<f32>(), b.try_downcast_ref::<f32>()) {
+        return Some(a.to_bits() == b.to_bits());
+    }
+    if let (Some(a), Some(b)) = (a.try_downcast_ref::<f64>(), b.try_downcast_ref::<f64>()) {
+        return Some(a.to_bits() == b.to_bits());
+    }
+
+    match a.reflect_ref() {
+        ReflectRef::Tuple(a) => tuple_partial_eq_total(a, b),
+        ReflectRef::TupleStruct(a) => crate::tuple_struct_partial_eq_total(a, b),
+        _ => a.reflect_partial_eq(b),
+    }
+}
+
+/// Returns `true` if `value` is a tuple whose fields have the same types as the tuple type `T`.
+///
+/// Fields are compared using their represented type, so dynamic fields that represent the
+/// expected type also match. This can be used to validate reflected data before calling
+/// [`FromReflect::from_reflect`].
+///
+/// # Example
+/// ```
+/// use bevy_reflect::is_tuple_of;
+///
+/// assert!(is_tuple_of::<(i32, f32)>(&(1_i32, 2.0_f32)));
+/// assert!(!is_tuple_of::<(i32, f32)>(&(1_i32, 2.0_f64)));
+/// assert!(!is_tuple_of::<(i32, f32)>(&(1_i32,)));
+/// ```
+pub fn is_tuple_of<T: Tuple + Typed>(value: &dyn PartialReflect) -> bool {
+    let (Ok(info), ReflectRef::Tuple(tuple)) = (T::type_info().as_tuple(), value.reflect_ref())
+    else {
+        return false;
+    };
+
+    info.field_len() == tuple.field_len()
+        && info.iter().zip(tuple.iter_fields()).all(|(field, value)| {
+            value
+                .get_represented_type_info()
+                .is_some_and(|value_info| value_info.type_id() == field.type_id())
+        })
+}
+
 /// The default debug formatter for [`Tuple`] types.
 ///
 /// # Example
@@ -501,6 +884,13 @@ macro_rules! impl_reflect_tuple {
                 }
             }
 
+            #[inline]
+            fn iter_fields_mut(&mut self) -> TupleFieldIterMut {
+                TupleFieldIterMut::new(vec![
+                    $(&mut self.$index as &mut dyn PartialReflect,)*
+                ])
+            }
+
             #[inline]
             fn drain(self: Box<Self>) -> Vec<Box<dyn PartialReflect>> {
                 vec![
@@ -643,6 +1033,11 @@ macro_rules! impl_reflect_tuple {
             fn from_reflect(reflect: &dyn PartialReflect) -> Option<Self> {
                 let _ref_tuple = reflect.reflect_ref().as_tuple().ok()?;
 
+                const FIELD_LEN: usize = <[usize]>::len(&[$($index),*]);
+                if _ref_tuple.field_len() != FIELD_LEN {
+                    return None;
+                }
+
                 Some(
                     (
                         $(
@@ -759,7 +1154,12 @@ const _: () = {
 
 #[cfg(test)]
 mod tests {
-    use super::Tuple;
+    use super::{DynamicTuple, GetTupleField, GetTupleFieldError, Tuple};
+    use crate as bevy_reflect;
+    use crate::{
+        ApplyError, FromReflect, PartialReflect, Reflect, ReflectOwned, TypeInfo, TypePath, Typed,
+    };
+    use core::any::TypeId;
 
     #[test]
     fn next_index_increment() {
@@ -776,4 +1176,552 @@ mod tests {
         assert!(iter.next().is_none());
         assert_eq!(size, iter.index);
     }
+
+    #[test]
+    fn iter_fields_mut() {
+        let mut tuple = (1_i32, 2_i32, 3_i32);
+        let iter = tuple.iter_fields_mut();
+        assert_eq!(iter.len(), 3);
+        for field in iter {
+            *field.try_downcast_mut::<i32>().unwrap() *= 10;
+        }
+        assert_eq!((10, 20, 30), tuple);
+
+        let mut dynamic = DynamicTuple::default();
+        dynamic.insert(1_u8);
+        dynamic.insert(2_u8);
+        for field in dynamic.iter_fields_mut() {
+            *field.try_downcast_mut::<u8>().unwrap() += 1;
+        }
+        assert_eq!(Some(&2), dynamic.get_field::<u8>(0));
+        assert_eq!(Some(&3), dynamic.get_field::<u8>(1));
+    }
+
+    #[test]
+    fn dynamic_tuple_hash() {
+        let mut a = DynamicTuple::default();
+        a.insert(1_u32);
+        a.insert(String::from("foo"));
+
+        let mut b = DynamicTuple::default();
+        b.insert(1_u32);
+        b.insert(String::from("foo"));
+
+        assert!(a.reflect_hash().is_some());
+        assert_eq!(a.reflect_hash(), b.reflect_hash());
+
+        b.field_mut(0).unwrap().apply(&2_u32);
+        assert_ne!(a.reflect_hash(), b.reflect_hash());
+
+        // `f32` does not support hashing
+        a.insert(1.0_f32);
+        assert!(a.reflect_hash().is_none());
+    }
+
+    #[test]
+    fn dynamic_tuple_insert() {
+        let mut tuple = (1_u8, 2_u16).clone_dynamic();
+        assert_eq!(
+            Some(<(u8, u16) as TypePath>::type_path()),
+            tuple.get_represented_type_info().map(TypeInfo::type_path)
+        );
+
+        tuple.insert(3_u32);
+        tuple.insert_boxed(Box::new(4_u64));
+        assert_eq!(4, tuple.field_len());
+        assert_eq!(Some(&3), tuple.get_field::<u32>(2));
+        assert_eq!(Some(&4), tuple.get_field::<u64>(3));
+        // The tuple no longer matches the type it was cloned from
+        assert!(tuple.get_represented_type_info().is_none());
+    }
+
+    #[test]
+    fn dynamic_tuple_remove() {
+        let mut tuple = (1_u8, 2_u16, 3_u32).clone_dynamic();
+        assert!(tuple.get_represented_type_info().is_some());
+
+        let removed = tuple.remove(1).unwrap();
+        assert_eq!(Some(&2), removed.try_downcast_ref::<u16>());
+        assert_eq!(2, tuple.field_len());
+        assert_eq!(Some(&1), tuple.get_field::<u8>(0));
+        assert_eq!(Some(&3), tuple.get_field::<u32>(1));
+        // The tuple no longer matches the type it was cloned from
+        assert!(tuple.get_represented_type_info().is_none());
+
+        assert!(tuple.remove(2).is_none());
+        assert_eq!(2, tuple.field_len());
+    }
+
+    #[test]
+    fn dynamic_tuple_set_field() {
+        let mut tuple = (1_u8, 2_u16).clone_dynamic();
+
+        let old = tuple.set_field(0, Box::new(3_u8)).unwrap();
+        assert_eq!(Some(&1), old.try_downcast_ref::<u8>());
+        assert_eq!(Some(&3), tuple.get_field::<u8>(0));
+        assert_eq!(2, tuple.field_len());
+        assert!(
+            tuple.get_represented_type_info().is_some(),
+            "same-typed replacement should keep the represented type"
+        );
+
+        let old = tuple.set_field(1, Box::new(4_u32)).unwrap();
+        assert_eq!(Some(&2), old.try_downcast_ref::<u16>());
+        assert_eq!(Some(&4), tuple.get_field::<u32>(1));
+        assert!(tuple.get_represented_type_info().is_none());
+
+        assert!(tuple.set_field(2, Box::new(5_u8)).is_none());
+        assert_eq!(2, tuple.field_len());
+    }
+
+    #[test]
+    fn dynamic_tuple_set_nested_dynamic_field() {
+        let mut tuple = ((1_u8,), 2_u16).clone_dynamic();
+
+        // Nested dynamic tuples share a type path, so their represented types are compared
+        tuple.set_field(0, Box::new((3_u8,).clone_dynamic()));
+        assert_eq!(
+            Some(<((u8,), u16)>::type_path()),
+            tuple.get_represented_type_info().map(TypeInfo::type_path),
+            "replacing a field with one representing the same type should keep the represented type"
+        );
+
+        tuple.set_field(0, Box::new((4_u16,).clone_dynamic()));
+        assert!(tuple.get_represented_type_info().is_none());
+
+        // A dynamic value with no represented type may not match anything
+        let mut tuple = ((1_u8,), 2_u16).clone_dynamic();
+        let mut unrepresented = DynamicTuple::default();
+        unrepresented.insert(3_u8);
+        tuple.set_field(0, Box::new(unrepresented));
+        assert!(tuple.get_represented_type_info().is_none());
+    }
+
+    #[test]
+    fn dynamic_tuple_swap() {
+        let mut tuple = (1_u8, 2_u16, 3_u8).clone_dynamic();
+
+        tuple.swap(0, 2);
+        assert_eq!(Some(&3), tuple.get_field::<u8>(0));
+        assert_eq!(Some(&2), tuple.get_field::<u16>(1));
+        assert_eq!(Some(&1), tuple.get_field::<u8>(2));
+        assert_eq!(
+            Some(<(u8, u16, u8)>::type_path()),
+            tuple.get_represented_type_info().map(TypeInfo::type_path),
+            "swapping same-typed fields should keep the represented type"
+        );
+
+        tuple.swap(0, 1);
+        assert_eq!(Some(&2), tuple.get_field::<u16>(0));
+        assert_eq!(Some(&3), tuple.get_field::<u8>(1));
+        assert!(tuple.get_represented_type_info().is_none());
+        assert_eq!(
+            Some((2_u16, 3_u8, 1_u8)),
+            <(u16, u8, u8)>::from_reflect(&tuple)
+        );
+    }
+
+    #[test]
+    fn dynamic_tuple_swap_nested_dynamic_fields() {
+        let mut tuple = ((1_u8,), (2_u8,)).clone_dynamic();
+        tuple.swap(0, 1);
+        assert_eq!(
+            Some(<((u8,), (u8,))>::type_path()),
+            tuple.get_represented_type_info().map(TypeInfo::type_path),
+            "swapping fields representing the same type should keep the represented type"
+        );
+
+        // Both fields are `DynamicTuple`s, but they represent different types
+        let mut tuple = ((1_u8,), (2_u16,)).clone_dynamic();
+        tuple.swap(0, 1);
+        assert!(tuple.get_represented_type_info().is_none());
+        assert_eq!(
+            Some(((2_u16,), (1_u8,))),
+            <((u16,), (u8,))>::from_reflect(&tuple)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn dynamic_tuple_swap_out_of_bounds() {
+        let mut tuple = (1_u8, 2_u16).clone_dynamic();
+        tuple.swap(0, 2);
+    }
+
+    #[test]
+    fn dynamic_tuple_debug() {
+        #[derive(Reflect)]
+        struct NoDebug;
+
+        let mut tuple = DynamicTuple::default();
+        tuple.insert(1_i32);
+        tuple.insert(2.0_f32);
+        assert_eq!("DynamicTuple((1, 2.0))", format!("{tuple:?}"));
+
+        // Fields are formatted through reflection, so they need not implement `Debug`
+        tuple.insert(NoDebug);
+        assert!(format!("{tuple:?}").contains("NoDebug"));
+    }
+
+    #[test]
+    fn dynamic_tuple_insert_placeholder() {
+        let mut tuple = DynamicTuple::default();
+        tuple.insert_placeholder();
+        tuple.insert_placeholder();
+        assert_eq!(2, tuple.field_len());
+        assert_eq!(None, <(u8, u16)>::from_reflect(&tuple));
+
+        tuple.set_field(1, Box::new(2_u16));
+        tuple.set_field(0, Box::new(1_u8));
+        assert_eq!(Some((1, 2)), <(u8, u16)>::from_reflect(&tuple));
+    }
+
+    #[test]
+    fn dynamic_tuple_drain_fields() {
+        let mut tuple = (1_u8, 2_u16).clone_dynamic();
+
+        let fields = tuple.drain_fields();
+        assert_eq!(2, fields.len());
+        assert_eq!(Some(&1), fields[0].try_downcast_ref::<u8>());
+        assert_eq!(Some(&2), fields[1].try_downcast_ref::<u16>());
+        assert_eq!(0, tuple.field_len());
+        assert!(tuple.get_represented_type_info().is_none());
+
+        tuple.insert(3_u32);
+        assert_eq!(1, tuple.field_len());
+        assert_eq!(Some(&3), tuple.get_field::<u32>(0));
+    }
+
+    #[test]
+    fn dynamic_tuple_clone() {
+        let tuple = (1_u8, String::from("foo")).clone_dynamic();
+        let cloned = tuple.clone();
+
+        assert_eq!(Some(true), tuple.reflect_partial_eq(&cloned));
+        assert_eq!(
+            tuple.get_represented_type_info().map(TypeInfo::type_path),
+            cloned.get_represented_type_info().map(TypeInfo::type_path)
+        );
+    }
+
+    #[test]
+    fn from_reflect_dynamic_tuple() {
+        let mut dynamic = DynamicTuple::default();
+        dynamic.insert(1_i32);
+        dynamic.insert(String::from("foo"));
+
+        let tuple = <(i32, String)>::from_reflect(&dynamic);
+        assert_eq!(Some((1, String::from("foo"))), tuple);
+
+        // Mismatched field types
+        assert_eq!(None, <(String, i32)>::from_reflect(&dynamic));
+        // Mismatched field counts
+        assert_eq!(None, <(i32,)>::from_reflect(&dynamic));
+        assert_eq!(None, <(i32, String, bool)>::from_reflect(&dynamic));
+        // Not a tuple
+        assert_eq!(None, <(i32,)>::from_reflect(&1_i32));
+    }
+
+    #[test]
+    fn try_apply_should_detect_field_count_mismatch() {
+        let mut tuple = (1_i32, 2_i32);
+
+        let result = tuple.try_apply(&(3_i32,));
+        assert!(matches!(
+            result,
+            Err(ApplyError::DifferentSize {
+                from_size: 1,
+                to_size: 2
+            })
+        ));
+
+        let result = tuple.try_apply(&(3_i32, 4_i32, 5_i32));
+        assert!(matches!(
+            result,
+            Err(ApplyError::DifferentSize {
+                from_size: 3,
+                to_size: 2
+            })
+        ));
+
+        // Nothing should have been applied
+        assert_eq!((1, 2), tuple);
+
+        let result = tuple.try_apply(&(3_i32, String::from("foo")));
+        assert!(matches!(result, Err(ApplyError::MismatchedTypes { .. })));
+
+        tuple.try_apply(&(3_i32, 4_i32)).unwrap();
+        assert_eq!((3, 4), tuple);
+    }
+
+    #[test]
+    fn try_get_field() {
+        let tuple = (1_i32, String::from("foo"));
+        assert_eq!(Ok(&1), tuple.try_get_field::<i32>(0));
+        assert_eq!(
+            Err(GetTupleFieldError::IndexOutOfBounds {
+                index: 2,
+                field_len: 2
+            }),
+            tuple.try_get_field::<i32>(2)
+        );
+        assert_eq!(
+            Err(GetTupleFieldError::MismatchedTypes {
+                expected: "i32".into(),
+                found: "alloc::string::String".into()
+            }),
+            tuple.try_get_field::<i32>(1)
+        );
+
+        let dynamic: &dyn Tuple = &tuple;
+        assert_eq!(Ok(&String::from("foo")), dynamic.try_get_field::<String>(1));
+        assert!(matches!(
+            dynamic.try_get_field::<String>(0),
+            Err(GetTupleFieldError::MismatchedTypes { .. })
+        ));
+    }
+
+    #[test]
+    fn get_field_by_type_id() {
+        let tuple = (1_u8, String::from("foo"));
+
+        let by_type = tuple.get_field::<String>(1).unwrap();
+        let by_type_id = tuple
+            .get_field_by_type_id(1, TypeId::of::<String>())
+            .unwrap();
+        assert!(core::ptr::eq(
+            by_type,
+            by_type_id.as_any().downcast_ref::<String>().unwrap()
+        ));
+
+        assert!(tuple.get_field_by_type_id(0, TypeId::of::<u16>()).is_none());
+        assert!(tuple.get_field_by_type_id(2, TypeId::of::<u8>()).is_none());
+
+        let dynamic: &dyn Tuple = &tuple;
+        assert_eq!(
+            dynamic.get_field::<u8>(0),
+            dynamic
+                .get_field_by_type_id(0, TypeId::of::<u8>())
+                .and_then(|value| value.as_any().downcast_ref::<u8>())
+        );
+    }
+
+    #[test]
+    fn arity_matches() {
+        let expected = <(u8, u16)>::type_info().as_tuple().unwrap().field_len();
+
+        let tuple = (1_u8, 2_u16).clone_dynamic();
+        assert!(tuple.arity_matches(expected));
+
+        let tuple: &dyn Tuple = &(1_u8, 2_u16, 3_u32);
+        assert!(!tuple.arity_matches(expected));
+        assert!(tuple.arity_matches(3));
+    }
+
+    #[test]
+    fn partial_eq_with_itself_compares_fields() {
+        // Comparing a value to itself must still defer to each field, since
+        // fields like `f32::NAN` are not equal to themselves.
+        let nan = (1_i32, f32::NAN);
+        assert_eq!(Some(false), nan.reflect_partial_eq(&nan));
+
+        let value = (1_i32, 2.0_f32);
+        assert_eq!(Some(true), value.reflect_partial_eq(&value));
+    }
+
+    #[test]
+    fn partial_eq_should_not_widen_numeric_fields() {
+        let mut dynamic = DynamicTuple::default();
+        dynamic.insert(1_i32);
+
+        assert_eq!(Some(true), dynamic.reflect_partial_eq(&(1_i32,)));
+        assert_eq!(Some(false), dynamic.reflect_partial_eq(&(1_i64,)));
+        assert_eq!(Some(false), (1_i32,).reflect_partial_eq(&(1_i64,)));
+    }
+
+    #[test]
+    fn partial_eq_total_treats_nan_as_equal() {
+        use crate::{tuple_partial_eq, tuple_partial_eq_total};
+
+        let a = (1_i32, f32::NAN, (f64::NAN,));
+        let b = a.clone_dynamic();
+        assert_eq!(Some(false), tuple_partial_eq(&a, &b));
+        assert_eq!(Some(true), tuple_partial_eq_total(&a, &b));
+
+        // Bits are compared, so differently signed zeros are distinct
+        assert_eq!(Some(true), tuple_partial_eq(&(0.0_f32,), &(-0.0_f32,)));
+        assert_eq!(
+            Some(false),
+            tuple_partial_eq_total(&(0.0_f32,), &(-0.0_f32,))
+        );
+
+        // Non-float fields still compare by value
+        assert_eq!(
+            Some(false),
+            tuple_partial_eq_total(&(1_i32, f32::NAN), &(2_i32, f32::NAN))
+        );
+        assert_eq!(
+            Some(false),
+            tuple_partial_eq_total(&(1_i32,), &(1_i32, 2_i32))
+        );
+    }
+
+    #[test]
+    fn partial_eq_between_dynamic_and_concrete() {
+        let concrete = (1_i32, 2.0_f32);
+
+        let mut dynamic = DynamicTuple::default();
+        dynamic.insert(1_i32);
+        dynamic.insert(2.0_f32);
+
+        assert_eq!(Some(true), concrete.reflect_partial_eq(&dynamic));
+        assert_eq!(Some(true), dynamic.reflect_partial_eq(&concrete));
+
+        dynamic.field_mut(1).unwrap().apply(&3.0_f32);
+        assert_eq!(Some(false), concrete.reflect_partial_eq(&dynamic));
+        assert_eq!(Some(false), dynamic.reflect_partial_eq(&concrete));
+
+        dynamic.insert(4_u8);
+        assert_eq!(Some(false), concrete.reflect_partial_eq(&dynamic));
+        assert_eq!(Some(false), dynamic.reflect_partial_eq(&concrete));
+    }
+
+    #[test]
+    fn dynamic_tuple_apply_should_grow() {
+        let mut tuple = DynamicTuple::default();
+        tuple.insert(1_u8);
+        tuple.insert(2_u16);
+
+        tuple.apply(&(3_u8, 4_u16, String::from("foo")));
+        assert_eq!(3, tuple.field_len());
+        assert_eq!(Some(&3), tuple.get_field::<u8>(0));
+        assert_eq!(Some(&4), tuple.get_field::<u16>(1));
+        assert_eq!(Some(&String::from("foo")), tuple.get_field::<String>(2));
+
+        // A shorter source is still rejected
+        let result = tuple.try_apply(&(5_u8,));
+        assert!(matches!(
+            result,
+            Err(ApplyError::DifferentSize {
+                from_size: 1,
+                to_size: 3
+            })
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to apply type with 1 size to a type with 2 size")]
+    fn apply_should_panic_on_field_count_mismatch() {
+        let mut tuple = (1_i32, 2_i32);
+        tuple.apply(&(3_i32,));
+    }
+
+    #[test]
+    fn nested_tuple_field_by_path() {
+        use crate::{access::Access, GetPath, ParsedPath};
+
+        let mut value = ((1_i32, 2_i32), 3_i32);
+        assert_eq!(*value.path::<i32>(".0.1").unwrap(), 2);
+
+        let path = ParsedPath::from([Access::TupleIndex(0), Access::TupleIndex(1)]);
+        *value.path_mut::<i32>(&path).unwrap() = 4;
+        assert_eq!(value, ((1, 4), 3));
+
+        // Out of range and non-tuple steps fail
+        assert!(value.path::<i32>(".0.2").is_err());
+        assert!(value.path::<i32>(".1.0").is_err());
+    }
+
+    #[test]
+    fn clone_dynamic_round_trips_through_from_reflect() {
+        let value = (1_i32, (2.0_f32, 3_u8));
+        let dynamic = value.clone_dynamic();
+
+        assert_eq!(
+            Some(<(i32, (f32, u8))>::type_path()),
+            dynamic.get_represented_type_info().map(TypeInfo::type_path)
+        );
+        // Opaque fields keep their concrete type, nested tuples become dynamic
+        assert!(dynamic
+            .field(0)
+            .unwrap()
+            .try_downcast_ref::<i32>()
+            .is_some());
+        let nested = dynamic.field(1).unwrap();
+        assert!(nested.is_dynamic());
+        assert!(nested.reflect_ref().as_tuple().is_ok());
+
+        assert_eq!(Some(value), <(i32, (f32, u8))>::from_reflect(&dynamic));
+    }
+
+    #[test]
+    fn dynamic_tuple_into_iter() {
+        let mut tuple = DynamicTuple::default();
+        tuple.insert(1_u8);
+        tuple.insert(String::from("foo"));
+        let len = tuple.field_len();
+
+        let iter = tuple.into_iter();
+        assert_eq!(len, iter.len());
+
+        let fields = iter.collect::<Vec<_>>();
+        assert_eq!(2, fields.len());
+        assert_eq!(Some(&1), fields[0].try_downcast_ref::<u8>());
+        assert_eq!(
+            Some(&String::from("foo")),
+            fields[1].try_downcast_ref::<String>()
+        );
+    }
+
+    #[test]
+    fn dynamic_tuple_index() {
+        let mut value = (1_u8, 2_u16).clone_dynamic();
+
+        assert_eq!(Some(&1), value[0].try_downcast_ref::<u8>());
+        *value[1].try_downcast_mut::<u16>().unwrap() = 3;
+        assert_eq!(Some(&3), value[1].try_downcast_ref::<u16>());
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn dynamic_tuple_index_out_of_bounds() {
+        let value = (1_u8, 2_u16).clone_dynamic();
+        let _ = &value[2];
+    }
+
+    #[test]
+    fn reflect_owned_tuple() {
+        let boxed: Box<dyn PartialReflect> = Box::new((1_i32, 2_i32));
+
+        let ReflectOwned::Tuple(tuple) = boxed.reflect_owned() else {
+            panic!("expected `ReflectOwned::Tuple`");
+        };
+        let dynamic: DynamicTuple = tuple.drain().into_iter().collect();
+
+        assert_eq!(2, dynamic.field_len());
+        assert_eq!(Some(&1), dynamic.get_field::<i32>(0));
+        assert_eq!(Some(&2), dynamic.get_field::<i32>(1));
+    }
+
+    #[test]
+    fn should_check_tuple_field_types() {
+        use super::is_tuple_of;
+
+        assert!(is_tuple_of::<(u8, String)>(&(1_u8, String::from("foo"))));
+        assert!(is_tuple_of::<(u8, String)>(
+            &(1_u8, String::from("foo")).clone_dynamic()
+        ));
+        assert!(is_tuple_of::<((u8, u16), u32)>(
+            &((1_u8, 2_u16), 3_u32).clone_dynamic()
+        ));
+
+        assert!(!is_tuple_of::<(u8, String)>(&(1_u16, String::from("foo"))));
+        assert!(!is_tuple_of::<(u8, String)>(&(1_u8,)));
+        assert!(!is_tuple_of::<(u8, String)>(&1_u8));
+
+        // Fields without a represented type cannot be checked
+        let mut unrepresented = DynamicTuple::default();
+        unrepresented.insert(DynamicTuple::default());
+        assert!(!is_tuple_of::<((),)>(&unrepresented));
+    }
 }
@@ -3,13 +3,18 @@ use bevy_reflect_derive::impl_type_path;
 use crate::{
     self as bevy_reflect,
     attributes::{impl_custom_attribute_methods, CustomAttributes},
+    tuple::field_partial_eq_total,
     type_info::impl_type_methods,
+    utility::reflect_hasher,
     ApplyError, DynamicTuple, PartialReflect, Reflect, ReflectKind, ReflectMut, ReflectOwned,
     ReflectRef, Tuple, Type, TypeInfo, TypePath, UnnamedField,
 };
 use alloc::sync::Arc;
 use core::{
+    any::Any,
     fmt::{Debug, Formatter},
+    hash::{Hash, Hasher},
+    ops::{Index, IndexMut},
     slice::Iter,
 };
 
@@ -54,6 +59,9 @@ pub trait TupleStruct: PartialReflect {
     /// Returns an iterator over the values of the tuple struct's fields.
     fn iter_fields(&self) -> TupleStructFieldIter;
 
+    /// Returns an iterator over mutable references to the values of the tuple struct's fields.
+    fn iter_fields_mut(&mut self) -> TupleStructFieldIterMut;
+
     /// Clones the struct into a [`DynamicTupleStruct`].
     fn clone_dynamic(&self) -> DynamicTupleStruct;
 }
@@ -156,6 +164,33 @@ impl<'a> Iterator for TupleStructFieldIter<'a> {
 
 impl<'a> ExactSizeIterator for TupleStructFieldIter<'a> {}
 
+/// A mutable iterator over the field values of a tuple struct.
+pub struct TupleStructFieldIterMut<'a> {
+    pub(crate) fields: alloc::vec::IntoIter<&'a mut dyn PartialReflect>,
+}
+
+impl<'a> TupleStructFieldIterMut<'a> {
+    pub fn new(fields: Vec<&'a mut dyn PartialReflect>) -> Self {
+        TupleStructFieldIterMut {
+            fields: fields.into_iter(),
+        }
+    }
+}
+
+impl<'a> Iterator for TupleStructFieldIterMut<'a> {
+    type Item = &'a mut dyn PartialReflect;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.fields.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.fields.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for TupleStructFieldIterMut<'a> {}
+
 /// A convenience trait which combines fetching and downcasting of tuple
 /// struct fields.
 ///
@@ -244,6 +279,21 @@ impl DynamicTupleStruct {
     pub fn insert<T: PartialReflect>(&mut self, value: T) {
         self.insert_boxed(Box::new(value));
     }
+
+    /// Removes the element at `index` from the tuple struct, shifting all elements after it down.
+    ///
+    /// Since the tuple struct no longer matches the type it represented, the represented
+    /// type is cleared.
+    ///
+    /// Returns [`None`] if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Option<Box<dyn PartialReflect>> {
+        if index >= self.fields.len() {
+            return None;
+        }
+
+        self.represented_type = None;
+        Some(self.fields.remove(index))
+    }
 }
 
 impl TupleStruct for DynamicTupleStruct {
@@ -270,6 +320,16 @@ impl TupleStruct for DynamicTupleStruct {
         }
     }
 
+    #[inline]
+    fn iter_fields_mut(&mut self) -> TupleStructFieldIterMut {
+        TupleStructFieldIterMut::new(
+            self.fields
+                .iter_mut()
+                .map(|field| &mut **field as &mut dyn PartialReflect)
+                .collect(),
+        )
+    }
+
     fn clone_dynamic(&self) -> DynamicTupleStruct {
         DynamicTupleStruct {
             represented_type: self.represented_type,
@@ -352,6 +412,11 @@ impl PartialReflect for DynamicTupleStruct {
         Box::new(self.clone_dynamic())
     }
 
+    #[inline]
+    fn reflect_hash(&self) -> Option<u64> {
+        tuple_struct_hash(self)
+    }
+
     #[inline]
     fn reflect_partial_eq(&self, value: &dyn PartialReflect) -> Option<bool> {
         tuple_struct_partial_eq(self, value)
@@ -377,6 +442,36 @@ impl Debug for DynamicTupleStruct {
     }
 }
 
+impl Clone for DynamicTupleStruct {
+    fn clone(&self) -> Self {
+        self.clone_dynamic()
+    }
+}
+
+/// Returns the field at `index`.
+///
+/// # Panics
+///
+/// Panics if `index` is out of bounds.
+impl Index<usize> for DynamicTupleStruct {
+    type Output = dyn PartialReflect;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &*self.fields[index]
+    }
+}
+
+/// Returns the field at `index` mutably.
+///
+/// # Panics
+///
+/// Panics if `index` is out of bounds.
+impl IndexMut<usize> for DynamicTupleStruct {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut *self.fields[index]
+    }
+}
+
 impl From<DynamicTuple> for DynamicTupleStruct {
     fn from(value: DynamicTuple) -> Self {
         Self {
@@ -386,6 +481,12 @@ impl From<DynamicTuple> for DynamicTupleStruct {
     }
 }
 
+impl From<DynamicTupleStruct> for DynamicTuple {
+    fn from(value: DynamicTupleStruct) -> Self {
+        value.into_iter().collect()
+    }
+}
+
 impl FromIterator<Box<dyn PartialReflect>> for DynamicTupleStruct {
     fn from_iter<I: IntoIterator<Item = Box<dyn PartialReflect>>>(fields: I) -> Self {
         Self {
@@ -413,6 +514,20 @@ impl<'a> IntoIterator for &'a DynamicTupleStruct {
     }
 }
 
+/// Returns the `u64` hash of the given [tuple struct](TupleStruct).
+///
+/// Returns [`None`] if any of the tuple struct's fields does not support hashing.
+#[inline]
+pub fn tuple_struct_hash<S: TupleStruct + ?Sized>(tuple_struct: &S) -> Option<u64> {
+    let mut hasher = reflect_hasher();
+    Any::type_id(tuple_struct).hash(&mut hasher);
+    tuple_struct.field_len().hash(&mut hasher);
+    for value in tuple_struct.iter_fields() {
+        hasher.write_u64(value.reflect_hash()?);
+    }
+    Some(hasher.finish())
+}
+
 /// Compares a [`TupleStruct`] with a [`PartialReflect`] value.
 ///
 /// Returns true if and only if all of the following are true:
@@ -421,6 +536,9 @@ impl<'a> IntoIterator for &'a DynamicTupleStruct {
 /// - [`PartialReflect::reflect_partial_eq`] returns `Some(true)` for pairwise fields of `a` and `b`.
 ///
 /// Returns [`None`] if the comparison couldn't even be performed.
+///
+/// Floating-point fields follow IEEE semantics, so a tuple struct containing `NaN` is not
+/// equal to itself. Use [`tuple_struct_partial_eq_total`] to compare floats by their bits instead.
 #[inline]
 pub fn tuple_struct_partial_eq<S: TupleStruct + ?Sized>(
     a: &S,
@@ -448,6 +566,38 @@ pub fn tuple_struct_partial_eq<S: TupleStruct + ?Sized>(
     Some(true)
 }
 
+/// Compares a [`TupleStruct`] with a [`PartialReflect`] value, treating bit-identical floats
+/// as equal.
+///
+/// This is the tuple struct counterpart to [`tuple_partial_eq_total`]: it behaves like
+/// [`tuple_struct_partial_eq`], except that floats are compared by their bits, so `NaN`
+/// equals itself and `0.0` does not equal `-0.0`.
+///
+/// Returns [`None`] if the comparison couldn't even be performed.
+///
+/// [`tuple_partial_eq_total`]: crate::tuple_partial_eq_total
+pub fn tuple_struct_partial_eq_total<S: TupleStruct + ?Sized>(
+    a: &S,
+    b: &dyn PartialReflect,
+) -> Option<bool> {
+    let ReflectRef::TupleStruct(tuple_struct) = b.reflect_ref() else {
+        return Some(false);
+    };
+
+    if a.field_len() != tuple_struct.field_len() {
+        return Some(false);
+    }
+
+    for (a_field, b_field) in a.iter_fields().zip(tuple_struct.iter_fields()) {
+        let eq_result = field_partial_eq_total(a_field, b_field);
+        if let failed @ (Some(false) | None) = eq_result {
+            return failed;
+        }
+    }
+
+    Some(true)
+}
+
 /// The default debug formatter for [`TupleStruct`] types.
 ///
 /// # Example
@@ -503,4 +653,202 @@ mod tests {
         assert!(iter.next().is_none());
         assert_eq!(size, iter.index);
     }
+
+    #[test]
+    fn iter_fields_mut() {
+        #[derive(Reflect, PartialEq, Debug)]
+        struct Foo(i32, i32);
+
+        let mut foo = Foo(1, 2);
+        let iter = foo.iter_fields_mut();
+        assert_eq!(iter.len(), 2);
+        for field in iter {
+            *field.try_downcast_mut::<i32>().unwrap() *= 10;
+        }
+        assert_eq!(Foo(10, 20), foo);
+
+        let mut dynamic = DynamicTupleStruct::default();
+        dynamic.insert(1_u8);
+        dynamic.insert(2_u8);
+        for field in dynamic.iter_fields_mut() {
+            *field.try_downcast_mut::<u8>().unwrap() += 1;
+        }
+        assert_eq!(Some(&2), dynamic.get_field::<u8>(0));
+        assert_eq!(Some(&3), dynamic.get_field::<u8>(1));
+    }
+
+    #[test]
+    fn dynamic_tuple_struct_hash() {
+        let mut a = DynamicTupleStruct::default();
+        a.insert(1_u32);
+        a.insert(String::from("foo"));
+
+        let mut b = DynamicTupleStruct::default();
+        b.insert(1_u32);
+        b.insert(String::from("foo"));
+
+        assert!(a.reflect_hash().is_some());
+        assert_eq!(a.reflect_hash(), b.reflect_hash());
+
+        b.field_mut(0).unwrap().apply(&2_u32);
+        assert_ne!(a.reflect_hash(), b.reflect_hash());
+
+        // `f32` does not support hashing
+        a.insert(1.0_f32);
+        assert!(a.reflect_hash().is_none());
+    }
+
+    #[test]
+    fn dynamic_tuple_struct_remove() {
+        #[derive(Reflect)]
+        struct Foo(u8, u16, u32);
+
+        let mut tuple_struct = Foo(1, 2, 3).clone_dynamic();
+        assert!(tuple_struct.get_represented_type_info().is_some());
+
+        let removed = tuple_struct.remove(1).unwrap();
+        assert_eq!(Some(&2), removed.try_downcast_ref::<u16>());
+        assert_eq!(2, tuple_struct.field_len());
+        assert_eq!(Some(&1), tuple_struct.get_field::<u8>(0));
+        assert_eq!(Some(&3), tuple_struct.get_field::<u32>(1));
+        // The tuple struct no longer matches the type it was cloned from
+        assert!(tuple_struct.get_represented_type_info().is_none());
+
+        assert!(tuple_struct.remove(2).is_none());
+        assert_eq!(2, tuple_struct.field_len());
+    }
+
+    #[test]
+    fn dynamic_tuple_struct_represented_type_path() {
+        #[derive(Reflect)]
+        struct MyStruct(u8, u8);
+
+        let dynamic = MyStruct(1, 2).clone_dynamic();
+
+        // The dynamic type keeps its own type path...
+        assert_eq!(DynamicTupleStruct::type_path(), dynamic.reflect_type_path());
+        // ...while the represented type carries the concrete one
+        assert_eq!(
+            Some(MyStruct::type_path()),
+            dynamic.get_represented_type_info().map(TypeInfo::type_path)
+        );
+    }
+
+    #[test]
+    fn dynamic_tuple_struct_clone() {
+        let mut tuple_struct = DynamicTupleStruct::default();
+        tuple_struct.insert(1_u8);
+        tuple_struct.insert(String::from("foo"));
+
+        let cloned = tuple_struct.clone();
+        assert_eq!(Some(true), tuple_struct.reflect_partial_eq(&cloned));
+    }
+
+    #[test]
+    fn dynamic_tuple_struct_index() {
+        let mut value = DynamicTupleStruct::default();
+        value.insert(1_u8);
+        value.insert(2_u16);
+
+        assert_eq!(Some(&1), value[0].try_downcast_ref::<u8>());
+        *value[1].try_downcast_mut::<u16>().unwrap() = 3;
+        assert_eq!(Some(&3), value[1].try_downcast_ref::<u16>());
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn dynamic_tuple_struct_index_out_of_bounds() {
+        let mut value = DynamicTupleStruct::default();
+        value.insert(1_u8);
+        value.insert(2_u16);
+        let _ = &value[2];
+    }
+
+    #[test]
+    fn dynamic_tuple_struct_to_tuple() {
+        let mut tuple_struct = DynamicTupleStruct::default();
+        tuple_struct.insert(1_u8);
+        tuple_struct.insert(String::from("foo"));
+
+        let tuple = DynamicTuple::from(tuple_struct.clone());
+        assert_eq!(tuple_struct.field_len(), tuple.field_len());
+        for (a, b) in tuple_struct.iter_fields().zip(tuple.iter_fields()) {
+            assert_eq!(Some(true), a.reflect_partial_eq(b));
+        }
+
+        let round_trip = DynamicTupleStruct::from(tuple);
+        assert_eq!(Some(true), tuple_struct.reflect_partial_eq(&round_trip));
+    }
+
+    #[test]
+    fn dynamic_tuple_struct_hash_ignores_represented_type() {
+        #[derive(Reflect)]
+        struct Foo(u32, u32);
+
+        #[derive(Reflect)]
+        struct Bar(u32, u32);
+
+        let foo = Foo(1, 2).clone_dynamic();
+        let bar = Bar(1, 2).clone_dynamic();
+        assert_ne!(
+            foo.get_represented_type_info().map(TypeInfo::type_path),
+            bar.get_represented_type_info().map(TypeInfo::type_path)
+        );
+        assert!(foo.reflect_hash().is_some());
+        assert_eq!(foo.reflect_hash(), bar.reflect_hash());
+
+        let mut unrepresented = DynamicTupleStruct::default();
+        unrepresented.insert(1_u32);
+        unrepresented.insert(2_u32);
+        assert_eq!(foo.reflect_hash(), unrepresented.reflect_hash());
+    }
+
+    #[test]
+    fn apply_at_nested_path() {
+        let mut inner = DynamicTupleStruct::default();
+        inner.insert(2_i32);
+        inner.insert(3_i32);
+
+        let mut value = DynamicTupleStruct::default();
+        value.insert(1_i32);
+        value.insert(inner);
+
+        let path = ParsedPath::from([Access::TupleIndex(1), Access::TupleIndex(0)]);
+        path.reflect_element_mut(&mut value)
+            .unwrap()
+            .try_apply(&4_i32)
+            .unwrap();
+
+        assert_eq!(Some(&1), value.get_field::<i32>(0));
+        let inner = value
+            .field(1)
+            .unwrap()
+            .reflect_ref()
+            .as_tuple_struct()
+            .unwrap();
+        assert_eq!(Some(&4), inner.get_field::<i32>(0));
+        assert_eq!(Some(&3), inner.get_field::<i32>(1));
+
+        // Paths that run past a leaf or out of range fail without applying anything
+        assert!(".1.2".reflect_element_mut(&mut value).is_err());
+        assert!(".0.0".reflect_element_mut(&mut value).is_err());
+    }
+
+    #[test]
+    fn partial_eq_total_treats_nan_as_equal() {
+        #[derive(Reflect)]
+        struct Foo(i32, f32);
+
+        let a = Foo(1, f32::NAN);
+        let b = a.clone_dynamic();
+        assert_eq!(Some(false), tuple_struct_partial_eq(&a, &b));
+        assert_eq!(Some(true), tuple_struct_partial_eq_total(&a, &b));
+
+        let c = Foo(2, f32::NAN);
+        assert_eq!(Some(false), tuple_struct_partial_eq_total(&a, &c));
+        assert_eq!(
+            Some(false),
+            tuple_struct_partial_eq_total(&a, &(1_i32, f32::NAN))
+        );
+    }
 }